@@ -0,0 +1,147 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{tokenize_words, WordStats};
+
+/// Corpus-level aggregation of term statistics across multiple documents,
+/// mirroring the frequency/document-frequency reporting `WordStats` does
+/// for a single document.
+#[pyclass]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CorpusStats {
+    pub documents: usize,
+    pub document_stats: Vec<WordStats>,
+    pub document_frequency: HashMap<String, usize>,
+    pub collection_frequency: HashMap<String, usize>,
+    pub top_words: Vec<(String, usize)>,
+    pub top_keywords_per_document: Vec<Vec<(String, f64)>>,
+}
+
+#[pymethods]
+impl CorpusStats {
+    #[new]
+    pub fn new() -> Self {
+        CorpusStats {
+            documents: 0,
+            document_stats: Vec::new(),
+            document_frequency: HashMap::new(),
+            collection_frequency: HashMap::new(),
+            top_words: Vec::new(),
+            top_keywords_per_document: Vec::new(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
+    }
+}
+
+impl Default for CorpusStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CorpusStats {
+    pub fn analyze(&mut self, docs: &[String]) {
+        self.documents = docs.len();
+
+        let per_doc_counts: Vec<HashMap<String, usize>> = docs.iter()
+            .map(|doc| {
+                let mut counts = HashMap::new();
+                for word in tokenize_words(doc) {
+                    *counts.entry(word).or_insert(0) += 1;
+                }
+                counts
+            })
+            .collect();
+
+        self.document_stats = docs.iter()
+            .map(|doc| {
+                let mut stats = WordStats::new();
+                stats.analyze(doc);
+                stats
+            })
+            .collect();
+
+        for counts in &per_doc_counts {
+            for (word, count) in counts {
+                *self.document_frequency.entry(word.clone()).or_insert(0) += 1;
+                *self.collection_frequency.entry(word.clone()).or_insert(0) += *count;
+            }
+        }
+
+        let mut collection_vec: Vec<(String, usize)> = self.collection_frequency
+            .iter()
+            .map(|(word, count)| (word.clone(), *count))
+            .collect();
+        collection_vec.sort_by(|a, b| b.1.cmp(&a.1));
+        self.top_words = collection_vec.into_iter().take(5).collect();
+
+        let num_docs = docs.len() as f64;
+        self.top_keywords_per_document = per_doc_counts.iter()
+            .map(|counts| {
+                let total_words: usize = counts.values().sum();
+                let mut tfidf: Vec<(String, f64)> = counts.iter()
+                    .map(|(word, count)| {
+                        let tf = *count as f64 / total_words.max(1) as f64;
+                        let document_frequency = self.document_frequency[word] as f64;
+                        let idf = (num_docs / document_frequency).ln();
+                        (word.clone(), tf * idf)
+                    })
+                    .collect();
+                tfidf.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                tfidf.into_iter().take(5).collect()
+            })
+            .collect();
+    }
+}
+
+#[pyfunction]
+pub fn analyze_corpus(docs: Vec<String>) -> CorpusStats {
+    let mut stats = CorpusStats::new();
+    stats.analyze(&docs);
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn document_and_collection_frequency_are_tracked_per_word() {
+        let docs = vec!["cat dog cat".to_string(), "dog bird".to_string()];
+        let mut stats = CorpusStats::new();
+        stats.analyze(&docs);
+
+        assert_eq!(stats.documents, 2);
+        assert_eq!(stats.document_frequency["cat"], 1);
+        assert_eq!(stats.document_frequency["dog"], 2);
+        assert_eq!(stats.collection_frequency["cat"], 2);
+        assert_eq!(stats.collection_frequency["dog"], 2);
+    }
+
+    #[test]
+    fn word_in_every_document_scores_zero_tfidf() {
+        let docs = vec!["cat dog".to_string(), "cat bird".to_string()];
+        let mut stats = CorpusStats::new();
+        stats.analyze(&docs);
+
+        let first_doc_keywords = &stats.top_keywords_per_document[0];
+        let cat_score = first_doc_keywords.iter().find(|(word, _)| word == "cat").unwrap().1;
+        assert!((cat_score - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rarer_word_ranks_above_word_in_every_document() {
+        let docs = vec!["cat dog".to_string(), "cat bird".to_string()];
+        let mut stats = CorpusStats::new();
+        stats.analyze(&docs);
+
+        let first_doc_keywords = &stats.top_keywords_per_document[0];
+        let dog_score = first_doc_keywords.iter().find(|(word, _)| word == "dog").unwrap().1;
+        let cat_score = first_doc_keywords.iter().find(|(word, _)| word == "cat").unwrap().1;
+        assert!(dog_score > cat_score);
+    }
+}
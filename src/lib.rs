@@ -3,6 +3,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Instant;
 
+mod corpus;
+mod sentence;
+mod stopwords;
+mod word_index;
+
+pub use corpus::{analyze_corpus, CorpusStats};
+pub use word_index::{build_word_index, WordIndex};
+
 #[pyclass]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WordStats {
@@ -17,6 +25,13 @@ pub struct WordStats {
     pub density: HashMap<String, f64>,
     pub top_words: Vec<(String, usize)>,
     pub longest_words: Vec<String>,
+    pub sentence_list: Vec<String>,
+    pub syllables: usize,
+    pub flesch_reading_ease: f64,
+    pub flesch_kincaid_grade: f64,
+    pub length_distribution: HashMap<usize, usize>,
+    #[serde(skip)]
+    length_buckets: HashMap<usize, Vec<String>>,
 }
 
 #[pymethods]
@@ -35,16 +50,32 @@ impl WordStats {
             density: HashMap::new(),
             top_words: Vec::new(),
             longest_words: Vec::new(),
+            sentence_list: Vec::new(),
+            syllables: 0,
+            flesch_reading_ease: 0.0,
+            flesch_kincaid_grade: 0.0,
+            length_distribution: HashMap::new(),
+            length_buckets: HashMap::new(),
         }
     }
 
     pub fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or_else(|_| "{}".to_string())
     }
+
+    /// Returns the unique vocabulary of exactly `n` characters, from the
+    /// length-indexed buckets built during `analyze`.
+    pub fn words_of_length(&self, n: usize) -> Vec<String> {
+        self.length_buckets.get(&n).cloned().unwrap_or_default()
+    }
 }
 
 impl WordStats {
     pub fn analyze(&mut self, text: &str) {
+        self.analyze_with_options(text, None, false);
+    }
+
+    pub fn analyze_with_options(&mut self, text: &str, lang: Option<&str>, filter_stopwords: bool) {
         // Character counts
         self.characters = text.chars().count();
         self.characters_no_spaces = text.chars()
@@ -58,20 +89,13 @@ impl WordStats {
             .collect();
         self.paragraphs = paragraphs.len();
         
-        // Sentence detection
-        let sentence_re = regex::Regex::new(r"[.!?]+[\s\n]+").unwrap();
-        let sentences: Vec<&str> = sentence_re
-            .split(text)
-            .filter(|s| !s.trim().is_empty())
-            .collect();
-        self.sentences = sentences.len();
+        // Sentence detection (abbreviation-aware, see `sentence` module)
+        self.sentence_list = sentence::split_sentences(text);
+        self.sentences = self.sentence_list.len();
         
         // Word detection
-        let word_re = regex::Regex::new(r"\b[\p{L}\p{M}']+(?:-[\p{L}\p{M}']+)*\b").unwrap();
-        let words: Vec<String> = word_re.find_iter(text)
-            .map(|m| m.as_str().to_lowercase())
-            .collect();
-        
+        let words = tokenize_words(text);
+
         self.words = words.len();
         
         // Calculate average word length
@@ -82,31 +106,125 @@ impl WordStats {
             total_letters as f64 / self.words as f64
         } else { 0.0 };
         
-        // Unique words
+        // Word counts (raw, including stopwords, so totals stay accurate)
         let mut word_counts = HashMap::new();
         for word in &words {
             *word_counts.entry(word.clone()).or_insert(0) += 1;
         }
-        self.unique_words = word_counts.len();
-        
-        // Top 5 most frequent words
-        let mut word_vec: Vec<(String, usize)> = word_counts.into_iter().collect();
+
+        // Keyword-oriented counts (top_words, density, unique_words) optionally drop stopwords
+        let stopword_set = if filter_stopwords {
+            lang.and_then(stopwords::stopword_set)
+        } else {
+            None
+        };
+        let keyword_counts: HashMap<String, usize> = match &stopword_set {
+            Some(stopwords) => word_counts.iter()
+                .filter(|(word, _)| !stopwords.contains(word.as_str()))
+                .map(|(word, count)| (word.clone(), *count))
+                .collect(),
+            None => word_counts.clone(),
+        };
+        self.unique_words = if filter_stopwords && stopword_set.is_some() {
+            keyword_counts.len()
+        } else {
+            word_counts.len()
+        };
+
+        // Top 5 most frequent (keyword) words
+        let mut word_vec: Vec<(String, usize)> = keyword_counts.into_iter().collect();
         word_vec.sort_by(|a, b| b.1.cmp(&a.1));
         self.top_words = word_vec.iter()
             .take(5)
             .map(|(word, count)| (word.clone(), *count))
             .collect();
-        
-        // Longest words
+
+        // Relative frequency ("density") of each keyword word
+        self.density = word_vec.iter()
+            .map(|(word, count)| (word.clone(), *count as f64 / self.words.max(1) as f64))
+            .collect();
+
+        // Unique vocabulary, deduped and bucketed by length
         let mut unique_words_set: Vec<String> = words.into_iter().collect();
         unique_words_set.sort();
         unique_words_set.dedup();
+
+        self.length_buckets.clear();
+        self.length_distribution.clear();
+        for word in &unique_words_set {
+            let len = word.chars().count();
+            self.length_buckets.entry(len).or_default().push(word.clone());
+            *self.length_distribution.entry(len).or_insert(0) += 1;
+        }
+
+        // Longest words
         unique_words_set.sort_by_key(|w| std::cmp::Reverse(w.len()));
         self.longest_words = unique_words_set.into_iter().take(5).collect();
         
         // Reading time (225 WPM)
         self.reading_time_seconds = (self.words as f64 / 225.0 * 60.0) as usize;
+
+        // Readability scoring (uses the raw, unfiltered word counts)
+        self.syllables = word_counts.iter()
+            .map(|(word, count)| count_syllables(word) * count)
+            .sum();
+        let words_per_sentence = if self.sentences > 0 {
+            self.words as f64 / self.sentences as f64
+        } else { 0.0 };
+        let syllables_per_word = if self.words > 0 {
+            self.syllables as f64 / self.words as f64
+        } else { 0.0 };
+        if self.words > 0 && self.sentences > 0 {
+            self.flesch_reading_ease = 206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word;
+            self.flesch_kincaid_grade = 0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59;
+        } else {
+            self.flesch_reading_ease = 0.0;
+            self.flesch_kincaid_grade = 0.0;
+        }
+    }
+}
+
+/// Counts syllables in a single lowercase word by grouping maximal runs of
+/// vowels (a, e, i, o, u, y), then adjusting for common silent-"e" endings.
+fn count_syllables(word: &str) -> usize {
+    let word = word.to_lowercase();
+    let chars: Vec<char> = word.chars().collect();
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+
+    let mut count: usize = 0;
+    let mut in_vowel_group = false;
+    for &c in &chars {
+        if is_vowel(c) {
+            if !in_vowel_group {
+                count += 1;
+            }
+            in_vowel_group = true;
+        } else {
+            in_vowel_group = false;
+        }
     }
+
+    let ends_in_silent_e = chars.len() > 3
+        && chars[chars.len() - 1] == 'e'
+        && !(chars.len() >= 2 && chars[chars.len() - 2] == 'l' && is_consonant(chars[chars.len() - 3]));
+    if ends_in_silent_e {
+        count = count.saturating_sub(1);
+    }
+
+    count.max(1)
+}
+
+fn is_consonant(c: char) -> bool {
+    c.is_alphabetic() && !matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// Tokenizes `text` into lowercased words, shared by per-document analysis
+/// and corpus-level aggregation so both count words the same way.
+pub(crate) fn tokenize_words(text: &str) -> Vec<String> {
+    let word_re = regex::Regex::new(r"\b[\p{L}\p{M}']+(?:-[\p{L}\p{M}']+)*\b").unwrap();
+    word_re.find_iter(text)
+        .map(|m| m.as_str().to_lowercase())
+        .collect()
 }
 
 #[pyfunction]
@@ -116,9 +234,114 @@ pub fn analyze_text_fast(text: &str) -> WordStats {
     stats
 }
 
+#[pyfunction]
+#[pyo3(signature = (text, lang=None, filter_stopwords=false))]
+pub fn analyze_text_with_options(text: &str, lang: Option<&str>, filter_stopwords: bool) -> WordStats {
+    let mut stats = WordStats::new();
+    stats.analyze_with_options(text, lang, filter_stopwords);
+    stats
+}
+
 #[pymodule]
 fn wdlib(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(analyze_text_fast, m)?)?;
+    m.add_function(wrap_pyfunction!(analyze_text_with_options, m)?)?;
+    m.add_function(wrap_pyfunction!(analyze_corpus, m)?)?;
+    m.add_function(wrap_pyfunction!(build_word_index, m)?)?;
     m.add_class::<WordStats>()?;
+    m.add_class::<CorpusStats>()?;
+    m.add_class::<WordIndex>()?;
     Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_simple_syllables() {
+        assert_eq!(count_syllables("cat"), 1);
+        assert_eq!(count_syllables("happy"), 2);
+        assert_eq!(count_syllables("banana"), 3);
+    }
+
+    #[test]
+    fn drops_trailing_silent_e() {
+        assert_eq!(count_syllables("make"), 1);
+        assert_eq!(count_syllables("time"), 1);
+    }
+
+    #[test]
+    fn keeps_consonant_le_syllable() {
+        assert_eq!(count_syllables("little"), 2);
+    }
+
+    #[test]
+    fn never_returns_zero() {
+        assert_eq!(count_syllables(""), 1);
+        assert_eq!(count_syllables("rhythm"), 1);
+    }
+
+    #[test]
+    fn flesch_scores_are_zero_without_sentences_or_words() {
+        let mut stats = WordStats::new();
+        stats.analyze("");
+        assert_eq!(stats.flesch_reading_ease, 0.0);
+        assert_eq!(stats.flesch_kincaid_grade, 0.0);
+    }
+
+    #[test]
+    fn length_distribution_counts_unique_words_by_length() {
+        let mut stats = WordStats::new();
+        stats.analyze("a cat a bat cats");
+
+        assert_eq!(stats.length_distribution.get(&1), Some(&1));
+        assert_eq!(stats.length_distribution.get(&3), Some(&2));
+        assert_eq!(stats.length_distribution.get(&4), Some(&1));
+        assert_eq!(stats.length_distribution.get(&2), None);
+    }
+
+    #[test]
+    fn words_of_length_returns_the_matching_bucket() {
+        let mut stats = WordStats::new();
+        stats.analyze("a cat a bat cats");
+
+        let mut three_letter_words = stats.words_of_length(3);
+        three_letter_words.sort();
+        assert_eq!(three_letter_words, vec!["bat".to_string(), "cat".to_string()]);
+
+        assert_eq!(stats.words_of_length(1), vec!["a".to_string()]);
+        assert!(stats.words_of_length(10).is_empty());
+    }
+
+    #[test]
+    fn stopword_filtering_removes_function_words_from_top_words_and_density() {
+        let mut stats = WordStats::new();
+        stats.analyze_with_options("the cat and the dog and the bird", Some("en"), true);
+
+        assert!(!stats.top_words.iter().any(|(word, _)| word == "the" || word == "and"));
+        assert!(!stats.density.contains_key("the"));
+        assert!(!stats.density.contains_key("and"));
+        assert!(stats.top_words.iter().any(|(word, _)| word == "cat"));
+
+        // Raw totals stay accurate even though keyword-oriented stats are filtered.
+        assert_eq!(stats.words, 8);
+    }
+
+    #[test]
+    fn stopword_filtering_is_skipped_without_a_language() {
+        let mut stats = WordStats::new();
+        stats.analyze_with_options("the cat and the dog", None, true);
+
+        assert!(stats.top_words.iter().any(|(word, _)| word == "the"));
+    }
+
+    #[test]
+    fn stopword_filtering_is_off_by_default() {
+        let mut stats = WordStats::new();
+        stats.analyze("the cat and the dog");
+
+        assert!(stats.top_words.iter().any(|(word, _)| word == "the"));
+        assert_eq!(stats.unique_words, 4);
+    }
 }
\ No newline at end of file
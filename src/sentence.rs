@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+/// Common abbreviations that end in a period without ending a sentence.
+/// Checked against the token stripped of its trailing period, lowercased.
+static COMMON_ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "etc", "inc",
+    "ltd", "co", "corp", "e.g", "i.e", "a.m", "p.m", "fig", "vol", "no",
+    "dept", "govt", "assn", "rev", "gen", "col", "capt", "approx", "est",
+];
+
+/// A minimum number of consistent (always-period-terminated) occurrences
+/// before a token seen only in this document is auto-treated as an
+/// abbreviation rather than a sentence end.
+const ABBREVIATION_FREQUENCY_THRESHOLD: usize = 3;
+
+/// Splits `text` into sentences using a Punkt-inspired, abbreviation-aware
+/// heuristic instead of a plain `[.!?]+\s+` regex, so titles ("Dr."),
+/// initials ("U.S.") and decimal numbers don't inflate the sentence count.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let tokens: Vec<&str> = text.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Vec::new();
+    }
+
+    let auto_abbreviations = detect_abbreviations(&tokens);
+
+    let mut sentences = Vec::new();
+    let mut current_start = 0;
+    for i in 0..tokens.len() {
+        let token = tokens[i];
+        let is_last_token = i == tokens.len() - 1;
+        if !ends_sentence_candidate(token) && !is_last_token {
+            continue;
+        }
+
+        let is_boundary = if !ends_sentence_candidate(token) {
+            false
+        } else if token.ends_with('!') || token.ends_with('?') {
+            true
+        } else {
+            is_true_sentence_end(
+                token,
+                tokens.get(i + 1).copied(),
+                &auto_abbreviations,
+            )
+        };
+
+        if is_boundary || is_last_token {
+            let sentence = tokens[current_start..=i].join(" ");
+            let trimmed = sentence.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            current_start = i + 1;
+        }
+    }
+
+    sentences
+}
+
+fn ends_sentence_candidate(token: &str) -> bool {
+    matches!(token.chars().last(), Some('.') | Some('!') | Some('?'))
+}
+
+/// Builds the frequency table of tokens seen with vs. without a trailing
+/// period and returns the subset that always ends in a period and appears
+/// often enough to be treated as a document-specific abbreviation.
+fn detect_abbreviations(tokens: &[&str]) -> HashSet<String> {
+    let mut with_period: HashMap<String, usize> = HashMap::new();
+    let mut without_period: HashMap<String, usize> = HashMap::new();
+
+    for token in tokens {
+        let core = token.trim_end_matches('.').to_lowercase();
+        if core.is_empty() {
+            continue;
+        }
+        if token.ends_with('.') {
+            *with_period.entry(core).or_insert(0) += 1;
+        } else {
+            *without_period.entry(core).or_insert(0) += 1;
+        }
+    }
+
+    with_period
+        .into_iter()
+        .filter(|(core, count)| {
+            *count >= ABBREVIATION_FREQUENCY_THRESHOLD && !without_period.contains_key(core)
+        })
+        .map(|(core, _)| core)
+        .collect()
+}
+
+/// Decides whether a token ending in a period is really a sentence end, or
+/// an abbreviation/initial that should keep the sentence going.
+fn is_true_sentence_end(token: &str, next_token: Option<&str>, auto_abbreviations: &HashSet<String>) -> bool {
+    let core = token.trim_end_matches('.').to_lowercase();
+    if core.is_empty() {
+        return true;
+    }
+
+    if COMMON_ABBREVIATIONS.contains(&core.as_str()) || auto_abbreviations.contains(&core) {
+        return false;
+    }
+
+    // A lone letter ("J.") or a run of dotted letters ("U.S.") is almost
+    // always an initial.
+    let is_initial = core.split('.').all(|part| {
+        let mut chars = part.chars();
+        matches!((chars.next(), chars.next()), (Some(c), None) if c.is_alphabetic())
+    });
+    if is_initial {
+        return false;
+    }
+
+    match next_token {
+        // A following word that doesn't start uppercase is very unlikely to
+        // start a new sentence, so treat the period as part of an abbreviation.
+        Some(next) => next.chars().next().is_none_or(|c| c.is_uppercase()),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_on_terminal_punctuation() {
+        let sentences = split_sentences("Hello world. How are you? Fine!");
+        assert_eq!(sentences, vec!["Hello world.", "How are you?", "Fine!"]);
+    }
+
+    #[test]
+    fn keeps_the_final_sentence_without_terminal_punctuation() {
+        assert_eq!(split_sentences("Hello! Bye"), vec!["Hello!", "Bye"]);
+        assert_eq!(split_sentences("Hello world"), vec!["Hello world"]);
+    }
+
+    #[test]
+    fn does_not_split_on_common_abbreviations() {
+        let sentences = split_sentences("Dr. Smith arrived. He left.");
+        assert_eq!(sentences, vec!["Dr. Smith arrived.", "He left."]);
+    }
+
+    #[test]
+    fn does_not_split_on_initials() {
+        let sentences = split_sentences("J. K. Rowling wrote this. It is long.");
+        assert_eq!(sentences, vec!["J. K. Rowling wrote this.", "It is long."]);
+    }
+
+    #[test]
+    fn empty_text_has_no_sentences() {
+        assert!(split_sentences("").is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+use crate::tokenize_words;
+
+/// A single node of the vocabulary trie. `frequency` is set only on nodes
+/// that terminate a word, so intermediate nodes stay `None`.
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    frequency: Option<usize>,
+}
+
+impl TrieNode {
+    fn insert(&mut self, word: &str, frequency: usize) {
+        let mut node = self;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.frequency = Some(frequency);
+    }
+}
+
+/// Character-trie index over a document's vocabulary, built from the word
+/// frequencies `analyze` already tracks. Backs prefix autocomplete and
+/// typo-tolerant ("fuzzy") lookups for editors and search boxes.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct WordIndex {
+    root: TrieNode,
+}
+
+#[pymethods]
+impl WordIndex {
+    #[new]
+    pub fn new() -> Self {
+        WordIndex::default()
+    }
+
+    /// (Re)builds the trie from the word frequencies found in `text`.
+    pub fn build(&mut self, text: &str) {
+        let mut counts = HashMap::new();
+        for word in tokenize_words(text) {
+            *counts.entry(word).or_insert(0) += 1;
+        }
+
+        self.root = TrieNode::default();
+        for (word, count) in counts {
+            self.root.insert(&word, count);
+        }
+    }
+
+    /// Walks down to `prefix`, then enumerates its subtree ranked by
+    /// descending frequency (ties broken alphabetically).
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<(String, usize)> {
+        let prefix_lower = prefix.to_lowercase();
+        let node = match self.find(&prefix_lower) {
+            Some(node) => node,
+            None => return Vec::new(),
+        };
+
+        let mut results = Vec::new();
+        collect_words(node, &prefix_lower, &mut results);
+        results.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        results.truncate(limit);
+        results
+    }
+
+    /// Returns every indexed word within `max_distance` edits of `query`,
+    /// most frequent first.
+    pub fn fuzzy(&self, query: &str, max_distance: usize) -> Vec<String> {
+        let query_lower = query.to_lowercase();
+        let query_chars: Vec<char> = query_lower.chars().collect();
+        let first_row: Vec<usize> = (0..=query_chars.len()).collect();
+
+        let mut matches = Vec::new();
+        for (&c, child) in &self.root.children {
+            fuzzy_walk(child, c, &query_chars, &first_row, max_distance, String::new(), &mut matches);
+        }
+
+        matches.sort_by(|a: &(String, usize), b: &(String, usize)| b.1.cmp(&a.1));
+        matches.into_iter().map(|(word, _)| word).collect()
+    }
+}
+
+impl WordIndex {
+    fn find(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+fn collect_words(node: &TrieNode, prefix: &str, results: &mut Vec<(String, usize)>) {
+    if let Some(frequency) = node.frequency {
+        results.push((prefix.to_string(), frequency));
+    }
+    for (&c, child) in &node.children {
+        let mut word = prefix.to_string();
+        word.push(c);
+        collect_words(child, &word, results);
+    }
+}
+
+/// One step of a Levenshtein-bounded trie walk: extends `word` with the
+/// node's character, computes the next edit-distance row from the parent
+/// row, and records a match whenever the row's last value is within bounds.
+fn fuzzy_walk(
+    node: &TrieNode,
+    ch: char,
+    query: &[char],
+    prev_row: &[usize],
+    max_distance: usize,
+    mut word: String,
+    matches: &mut Vec<(String, usize)>,
+) {
+    word.push(ch);
+
+    let mut row = vec![prev_row[0] + 1];
+    for (i, &qc) in query.iter().enumerate() {
+        let cost = if qc == ch { 0 } else { 1 };
+        let value = (row[i] + 1)
+            .min(prev_row[i + 1] + 1)
+            .min(prev_row[i] + cost);
+        row.push(value);
+    }
+
+    if *row.last().unwrap() <= max_distance {
+        if let Some(frequency) = node.frequency {
+            matches.push((word.clone(), frequency));
+        }
+    }
+
+    if row.iter().min().copied().unwrap_or(usize::MAX) <= max_distance {
+        for (&c, child) in &node.children {
+            fuzzy_walk(child, c, query, &row, max_distance, word.clone(), matches);
+        }
+    }
+}
+
+#[pyfunction]
+pub fn build_word_index(text: &str) -> WordIndex {
+    let mut index = WordIndex::new();
+    index.build(text);
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completes_prefix_ranked_by_frequency_with_alphabetic_tie_break() {
+        let mut index = WordIndex::new();
+        index.build("cat cat cat car care cap cap dog");
+
+        assert_eq!(
+            index.complete("ca", 10),
+            vec![
+                ("cat".to_string(), 3),
+                ("cap".to_string(), 2),
+                ("car".to_string(), 1),
+                ("care".to_string(), 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn complete_respects_limit() {
+        let mut index = WordIndex::new();
+        index.build("cat cat cat car care cap cap dog");
+
+        assert_eq!(index.complete("ca", 2), vec![("cat".to_string(), 3), ("cap".to_string(), 2)]);
+    }
+
+    #[test]
+    fn complete_returns_empty_for_unknown_prefix() {
+        let mut index = WordIndex::new();
+        index.build("cat dog");
+
+        assert!(index.complete("zzz", 10).is_empty());
+    }
+
+    #[test]
+    fn fuzzy_finds_exact_match_at_distance_zero() {
+        let mut index = WordIndex::new();
+        index.build("cat dog");
+
+        assert_eq!(index.fuzzy("cat", 0), vec!["cat".to_string()]);
+    }
+
+    #[test]
+    fn fuzzy_finds_one_edit_away() {
+        let mut index = WordIndex::new();
+        index.build("cat dog");
+
+        let mut matches = index.fuzzy("cot", 1);
+        matches.sort();
+        assert_eq!(matches, vec!["cat".to_string()]);
+    }
+
+    #[test]
+    fn fuzzy_finds_two_edits_away_but_not_at_one() {
+        let mut index = WordIndex::new();
+        index.build("cat dog");
+
+        assert!(index.fuzzy("cta", 1).is_empty());
+        assert_eq!(index.fuzzy("cta", 2), vec!["cat".to_string()]);
+    }
+
+    #[test]
+    fn fuzzy_misses_words_outside_the_distance_bound() {
+        let mut index = WordIndex::new();
+        index.build("cat dog");
+
+        assert!(index.fuzzy("elephant", 2).is_empty());
+    }
+
+    #[test]
+    fn empty_trie_and_empty_query_return_no_matches() {
+        let index = WordIndex::new();
+        assert!(index.complete("a", 10).is_empty());
+        assert!(index.fuzzy("a", 2).is_empty());
+
+        let mut built = WordIndex::new();
+        built.build("cat");
+        assert!(built.fuzzy("", 0).is_empty());
+    }
+}